@@ -0,0 +1,53 @@
+//! Runtime window-decoration toggling.
+//!
+//! On macOS, `set_decorations(true)` after the window started decorationless leaves the
+//! traffic-light controls floating above the webview instead of seated in the titlebar.
+//! Whenever decorations are turned back on, [`toggle_decorations`] forces a layout pass on
+//! the view that holds each traffic-light button so it returns to its standard frame, letting
+//! multi-page apps switch between custom-titlebar and native-titlebar pages without ghost
+//! controls. The buttons stay in the view that already holds them; none is moved into the
+//! content view.
+
+use tauri::WebviewWindow;
+
+#[tauri::command]
+pub fn toggle_decorations(window: WebviewWindow, decorations: bool) -> Result<(), String> {
+    window.set_decorations(decorations).map_err(|err| err.to_string())?;
+
+    #[cfg(target_os = "macos")]
+    if decorations {
+        reseat_traffic_lights(&window)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn reseat_traffic_lights(window: &WebviewWindow) -> Result<(), String> {
+    use cocoa::appkit::{NSWindow, NSWindowButton};
+    use cocoa::base::id;
+    use objc::{msg_send, sel, sel_impl};
+
+    let ns_window = window.ns_window().map_err(|err| err.to_string())? as id;
+
+    unsafe {
+        for button in [
+            NSWindowButton::NSWindowCloseButton,
+            NSWindowButton::NSWindowMiniaturizeButton,
+            NSWindowButton::NSWindowZoomButton,
+        ] {
+            let control: id = ns_window.standardWindowButton_(button);
+            if control.is_null() {
+                continue;
+            }
+            // Re-enabling decorations doesn't re-run AppKit's titlebar layout pass on its
+            // own; force it so the buttons snap back to their standard frame instead of
+            // staying wherever they drifted to while decorationless.
+            let superview: id = msg_send![control, superview];
+            let _: () = msg_send![superview, setNeedsLayout: true];
+            let _: () = msg_send![superview, layoutSubtreeIfNeeded];
+        }
+    }
+
+    Ok(())
+}
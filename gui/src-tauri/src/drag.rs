@@ -0,0 +1,84 @@
+//! Native OS drag-out of items from a duckling window (e.g. onto Finder/Explorer).
+//!
+//! Wraps `tauri-plugin-drag`'s `start_drag`. The upstream plugin crashes on macOS when an
+//! icon is passed in but can't be decoded; [`start_drag`] takes the icon as an explicitly
+//! optional [`DragIcon`] and [`decode_icon`] validates it so a missing or malformed icon degrades to "no icon"
+//! instead of aborting the process.
+
+use std::path::PathBuf;
+
+use tauri::image::Image;
+use tauri::WebviewWindow;
+
+/// An icon to show while dragging. The absence of one is `None` in [`start_drag`]'s `icon`.
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DragIcon {
+    /// Load the icon from an image file on disk.
+    Path(PathBuf),
+    /// Raw RGBA8 pixels, `width * height * 4` bytes.
+    Rgba { width: u32, height: u32, bytes: Vec<u8> },
+}
+
+fn decode_icon(icon: Option<DragIcon>) -> Option<Image<'static>> {
+    match icon {
+        None => None,
+        Some(DragIcon::Path(path)) => match Image::from_path(&path) {
+            Ok(image) => Some(image),
+            Err(err) => {
+                eprintln!("drag icon at {path:?} could not be decoded, dragging without one: {err}");
+                None
+            }
+        },
+        Some(DragIcon::Rgba { width, height, bytes }) => {
+            let expected_len = width as usize * height as usize * 4;
+            if bytes.len() != expected_len {
+                eprintln!(
+                    "drag icon declared {width}x{height} ({expected_len} bytes) but got {} bytes, dragging without one",
+                    bytes.len()
+                );
+                return None;
+            }
+            Some(Image::new_owned(bytes, width, height))
+        }
+    }
+}
+
+/// Start an OS-level drag session for `items` out of `window`, showing `icon` if one is
+/// given and decodable.
+#[tauri::command]
+pub fn start_drag(
+    window: WebviewWindow,
+    items: Vec<PathBuf>,
+    icon: Option<DragIcon>,
+) -> Result<(), String> {
+    let image = decode_icon(icon);
+    let drag_item = tauri_plugin_drag::DragItem::Files(items);
+    tauri_plugin_drag::start_drag(&window, drag_item, image).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_icon_decodes_to_none() {
+        assert!(decode_icon(None).is_none());
+    }
+
+    #[test]
+    fn mismatched_rgba_length_decodes_to_none_instead_of_panicking() {
+        let icon = DragIcon::Rgba {
+            width: 2,
+            height: 2,
+            bytes: vec![0u8; 4],
+        };
+        assert!(decode_icon(Some(icon)).is_none());
+    }
+
+    #[test]
+    fn missing_path_decodes_to_none() {
+        let icon = DragIcon::Path(PathBuf::from("/no/such/icon.png"));
+        assert!(decode_icon(Some(icon)).is_none());
+    }
+}
@@ -0,0 +1,120 @@
+//! Unified translucent window-effect layer.
+//!
+//! `window_vibrancy` exposes a different entry point per platform (vibrancy on macOS,
+//! Mica/Acrylic on Windows, blur on Linux). [`Effect`] collapses those into one enum so
+//! `run()` has a single call site, and [`Effect::apply`] degrades gracefully instead of
+//! panicking when the requested effect isn't available on the current OS/OS version.
+
+use tauri::WebviewWindow;
+use window_vibrancy::NSVisualEffectMaterial;
+
+/// A translucent/blur treatment to apply to a window.
+#[derive(Debug, Clone, Copy)]
+pub enum Effect {
+    /// macOS `NSVisualEffectView` vibrancy.
+    Vibrancy(NSVisualEffectMaterial),
+    /// Windows 11 Mica.
+    Mica,
+    /// Windows 10/11 Acrylic.
+    Acrylic,
+    /// Linux (X11/Wayland compositor) blur.
+    Blur,
+}
+
+impl Effect {
+    /// The effect to apply, read from the `DUCKLING_WINDOW_EFFECT` environment variable
+    /// (`vibrancy`, `mica`, `acrylic` or `blur`). Falls back to [`Effect::platform_default`]
+    /// when the variable is unset or holds an unrecognized value.
+    pub fn from_config() -> Self {
+        match std::env::var("DUCKLING_WINDOW_EFFECT") {
+            Ok(value) => match value.to_ascii_lowercase().as_str() {
+                "vibrancy" => Effect::Vibrancy(NSVisualEffectMaterial::Sidebar),
+                "mica" => Effect::Mica,
+                "acrylic" => Effect::Acrylic,
+                "blur" => Effect::Blur,
+                other => {
+                    eprintln!("unknown DUCKLING_WINDOW_EFFECT {other:?}, using platform default");
+                    Self::platform_default()
+                }
+            },
+            Err(_) => Self::platform_default(),
+        }
+    }
+
+    /// The effect duckling uses out of the box on the current platform.
+    pub fn platform_default() -> Self {
+        #[cfg(target_os = "macos")]
+        {
+            Effect::Vibrancy(NSVisualEffectMaterial::Sidebar)
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Effect::Mica
+        }
+        #[cfg(target_os = "linux")]
+        {
+            Effect::Blur
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+        {
+            Effect::Blur
+        }
+    }
+
+    /// Apply this effect to `window`, returning an error instead of panicking when the
+    /// current platform or OS version doesn't support it.
+    pub fn apply(self, window: &WebviewWindow) -> Result<(), String> {
+        match self {
+            Effect::Vibrancy(material) => Self::apply_vibrancy(window, material),
+            Effect::Mica => Self::apply_mica(window),
+            Effect::Acrylic => Self::apply_acrylic(window),
+            Effect::Blur => Self::apply_blur(window),
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn apply_vibrancy(window: &WebviewWindow, material: NSVisualEffectMaterial) -> Result<(), String> {
+        window_vibrancy::apply_vibrancy(
+            window,
+            material,
+            Some(window_vibrancy::NSVisualEffectState::FollowsWindowActiveState),
+            None,
+        )
+        .map_err(|err| format!("vibrancy unavailable: {err}"))
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    fn apply_vibrancy(_window: &WebviewWindow, _material: NSVisualEffectMaterial) -> Result<(), String> {
+        Err("vibrancy is only available on macOS".into())
+    }
+
+    #[cfg(target_os = "windows")]
+    fn apply_mica(window: &WebviewWindow) -> Result<(), String> {
+        window_vibrancy::apply_mica(window, None).map_err(|err| format!("Mica unavailable: {err}"))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn apply_mica(_window: &WebviewWindow) -> Result<(), String> {
+        Err("Mica is only available on Windows".into())
+    }
+
+    #[cfg(target_os = "windows")]
+    fn apply_acrylic(window: &WebviewWindow) -> Result<(), String> {
+        window_vibrancy::apply_acrylic(window, None).map_err(|err| format!("Acrylic unavailable: {err}"))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn apply_acrylic(_window: &WebviewWindow) -> Result<(), String> {
+        Err("Acrylic is only available on Windows".into())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn apply_blur(window: &WebviewWindow) -> Result<(), String> {
+        window_vibrancy::apply_blur(window, None).map_err(|err| format!("blur unavailable: {err}"))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn apply_blur(_window: &WebviewWindow) -> Result<(), String> {
+        Err("blur is only available on Linux".into())
+    }
+}
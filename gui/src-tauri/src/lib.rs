@@ -1,23 +1,95 @@
+mod decorations;
+#[cfg(not(mobile))]
+mod drag;
+#[cfg(not(mobile))]
+mod effects;
+
+#[cfg(not(mobile))]
+use effects::Effect;
+
+/// Whether duckling should start as a menubar/background "accessory" app with no Dock icon,
+/// read from the `DUCKLING_TRAY_ONLY` environment variable (`1`/`true` to enable). Defaults
+/// to a regular app; switch back to `Regular` at runtime once a real window needs to be
+/// shown.
+fn tray_only_mode() -> bool {
+    std::env::var("DUCKLING_TRAY_ONLY")
+        .map(|value| value == "1" || value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
-        .plugin(tauri_plugin_opener::init())
+    let builder = tauri::Builder::default().plugin(tauri_plugin_opener::init());
+
+    #[cfg(not(mobile))]
+    let builder = builder
+        .plugin(tauri_plugin_drag::init())
+        .invoke_handler(tauri::generate_handler![
+            drag::start_drag,
+            decorations::toggle_decorations,
+            set_tray_only
+        ]);
+
+    #[cfg(mobile)]
+    let builder = builder.plugin(tauri_plugin_haptics::init());
+
+    builder
         .setup(|app| {
-            #[cfg(target_os = "macos")]
+            use tauri::Manager;
+            let window = app.get_webview_window("main").unwrap();
+
+            #[cfg(not(mobile))]
             {
-                use tauri::Manager;
-                use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial, NSVisualEffectState};
-                let window = app.get_webview_window("main").unwrap();
-                apply_vibrancy(
-                    &window,
-                    NSVisualEffectMaterial::Sidebar,
-                    Some(NSVisualEffectState::FollowsWindowActiveState),
-                    None,
-                )
-                .expect("Failed to apply vibrancy");
+                if let Err(err) = Effect::from_config().apply(&window) {
+                    eprintln!("window effect unavailable, continuing without it: {err}");
+                }
+                set_activation_policy(app.handle(), tray_only_mode());
             }
+
+            #[cfg(mobile)]
+            configure_mobile_window(&window);
+
             Ok(())
         })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+/// Switch between tray-only (`Accessory`) and `Regular` at runtime, e.g. once a real
+/// window needs to be shown after starting in tray-only mode.
+#[tauri::command]
+fn set_tray_only(app: tauri::AppHandle, tray_only: bool) {
+    set_activation_policy(&app, tray_only);
+}
+
+/// Set the NSApplication activation policy to `Accessory` (no Dock icon, no app switcher
+/// entry) or `Regular`. No-op on every platform other than macOS.
+#[cfg(target_os = "macos")]
+fn set_activation_policy(app: &tauri::AppHandle, tray_only: bool) {
+    let policy = if tray_only {
+        tauri::ActivationPolicy::Accessory
+    } else {
+        tauri::ActivationPolicy::Regular
+    };
+    app.set_activation_policy(policy);
+}
+
+#[cfg(not(target_os = "macos"))]
+fn set_activation_policy(_app: &tauri::AppHandle, _tray_only: bool) {}
+
+/// Mobile-appropriate window setup, run in place of the desktop vibrancy/activation-policy
+/// path above. `window_vibrancy` links against desktop windowing libraries and must stay
+/// out of the mobile build entirely, so this branch is the only thing that touches the
+/// window on iOS/Android.
+#[cfg(mobile)]
+fn configure_mobile_window(window: &tauri::WebviewWindow) {
+    // Publish the safe-area insets as CSS custom properties so app styles can keep content
+    // clear of notches, the status bar and the home indicator/navigation bar.
+    let _ = window.eval(
+        "for (const side of ['top', 'right', 'bottom', 'left']) {\
+             document.documentElement.style.setProperty(\
+                 `--safe-area-inset-${side}`, `env(safe-area-inset-${side})`\
+             );\
+         }",
+    );
+}